@@ -0,0 +1,49 @@
+#[cfg(target_os = "windows")]
+mod win;
+#[cfg(target_os = "windows")]
+pub use win::*;
+
+/// A rectangle in screen pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// The shape of the system cursor, classified against the platform's
+/// built-in cursor set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorShape {
+    Arrow,
+    IBeam,
+    Wait,
+    Crosshair,
+    ResizeUp,
+    ResizeLeftRight,
+    ResizeUpDown,
+    ResizeUpLeftAndDownRight,
+    ResizeUpRightAndDownLeft,
+    ResizeAll,
+    OpenHand,
+    NotAllowed,
+    Appstarting,
+    Help,
+    Hidden,
+    Unknown,
+}
+
+/// A single on-screen, top-level window.
+#[derive(Debug, Clone)]
+pub struct Window {
+    pub window_id: u32,
+    pub name: String,
+    pub owner_name: String,
+    pub process_id: u32,
+    pub bounds: Bounds,
+    /// Position in the OS's top-to-bottom z-order: 0 is frontmost. Only
+    /// meaningful relative to other `Window`s returned by the same call to
+    /// `get_on_screen_windows`.
+    pub z_order: u32,
+}
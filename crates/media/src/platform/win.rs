@@ -6,22 +6,42 @@ use std::path::PathBuf;
 use super::{Bounds, CursorShape, Window};
 
 use windows::core::PCWSTR;
-use windows::Win32::Foundation::{CloseHandle, BOOL, FALSE, HWND, LPARAM, RECT, TRUE};
+use windows::Win32::Foundation::{
+    CloseHandle, BOOL, FALSE, HWND, LPARAM, LRESULT, POINT, RECT, TRUE, WPARAM,
+};
 use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_CLOAKED};
 use windows::Win32::Graphics::Gdi::{
-    EnumDisplayDevicesW, EnumDisplayMonitors, GetMonitorInfoW, DISPLAY_DEVICEW, HDC, HMONITOR,
-    MONITORINFO, MONITORINFOEXW,
+    CreateCompatibleDC, DeleteDC, DeleteObject, EnumDisplayDevicesW, EnumDisplayMonitors,
+    EnumDisplaySettingsW, GetDC, GetDIBits, GetMonitorInfoW, GetObjectW, ReleaseDC, BITMAP,
+    BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DEVMODEW, DIB_RGB_COLORS, DISPLAY_DEVICEW,
+    DISPLAY_DEVICE_ACTIVE, EDD_GET_DEVICE_INTERFACE_NAME, ENUM_CURRENT_SETTINGS, HBITMAP, HDC,
+    HMONITOR, MONITORINFO, MONITORINFOEXW, MONITORINFOF_PRIMARY,
 };
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::System::Threading::{
-    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_FORMAT, PROCESS_QUERY_LIMITED_INFORMATION,
+    GetCurrentThreadId, OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_FORMAT,
+    PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows::Win32::UI::Accessibility::{
+    SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK, WINEVENT_OUTOFCONTEXT,
+};
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, GetDpiForWindow, MDT_EFFECTIVE_DPI};
+use windows::Win32::UI::Input::{
+    GetRawInputData, RegisterRawInputDevices, HRAWINPUT, RAWINPUT, RAWINPUTDEVICE, RAWINPUTHEADER,
+    RIDEV_INPUTSINK, RIDEV_REMOVE, RID_INPUT, RIM_TYPEMOUSE,
 };
-use windows::Win32::UI::HiDpi::GetDpiForWindow;
 use windows::Win32::UI::WindowsAndMessaging::{
-    EnumWindows, GetCursorInfo, GetWindowRect, GetWindowTextLengthW, GetWindowTextW,
-    GetWindowThreadProcessId, IsWindowVisible, LoadCursorW, SetForegroundWindow, CURSORINFO,
-    IDC_APPSTARTING, IDC_ARROW, IDC_CROSS, IDC_HAND, IDC_HELP, IDC_IBEAM, IDC_NO, IDC_PERSON,
-    IDC_PIN, IDC_SIZEALL, IDC_SIZENESW, IDC_SIZENS, IDC_SIZENWSE, IDC_SIZEWE, IDC_UPARROW,
-    IDC_WAIT,
+    CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, EnumWindows, GetCursorInfo,
+    GetCursorPos, GetIconInfo, GetMessageW, GetSystemMetrics, GetWindowRect, GetWindowTextLengthW,
+    GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible, LoadCursorW, PostThreadMessageW,
+    RegisterClassW, SetForegroundWindow, TranslateMessage, CURSORINFO, CURSOR_SHOWING,
+    CW_USEDEFAULT, EVENT_OBJECT_CREATE, EVENT_OBJECT_DESTROY, EVENT_OBJECT_HIDE,
+    EVENT_OBJECT_LOCATIONCHANGE, EVENT_OBJECT_SHOW, EVENT_SYSTEM_FOREGROUND, HCURSOR, HWND_MESSAGE,
+    ICONINFO, IDC_APPSTARTING, IDC_ARROW, IDC_CROSS, IDC_HAND, IDC_HELP, IDC_IBEAM, IDC_NO,
+    IDC_PERSON, IDC_PIN, IDC_SIZEALL, IDC_SIZENESW, IDC_SIZENS, IDC_SIZENWSE, IDC_SIZEWE,
+    IDC_UPARROW, IDC_WAIT, MOUSE_MOVE_ABSOLUTE, MSG, OBJID_WINDOW, SM_CXVIRTUALSCREEN,
+    SM_CYVIRTUALSCREEN, SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN, WINDOW_EX_STYLE, WM_INPUT, WM_QUIT,
+    WNDCLASSW, WS_DISABLED,
 };
 
 #[inline]
@@ -59,6 +79,283 @@ pub fn get_cursor_shape(cursors: &DefaultCursors) -> CursorShape {
     }
 }
 
+/// The live cursor bitmap, decoded to top-down RGBA8, plus the metadata needed
+/// to composite it over a captured frame at the correct spot.
+pub struct CursorImage {
+    pub width: u32,
+    pub height: u32,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+    pub position: (i32, i32),
+    pub pixels: Vec<u8>,
+}
+
+/// Captures the actual cursor the user sees right now, including custom
+/// application cursors that `get_cursor_shape` can't classify against the
+/// system defaults.
+pub fn get_cursor_image() -> Option<CursorImage> {
+    let mut cursor_info = CURSORINFO {
+        cbSize: std::mem::size_of::<CURSORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetCursorInfo(&mut cursor_info) }.ok()?;
+
+    if cursor_info.flags != CURSOR_SHOWING {
+        return None;
+    }
+
+    unsafe { cursor_image_from_hcursor(cursor_info.hCursor) }.map(
+        |(width, height, hotspot_x, hotspot_y, pixels)| CursorImage {
+            width,
+            height,
+            hotspot_x,
+            hotspot_y,
+            position: (cursor_info.ptScreenPos.x, cursor_info.ptScreenPos.y),
+            pixels,
+        },
+    )
+}
+
+unsafe fn cursor_image_from_hcursor(hcursor: HCURSOR) -> Option<(u32, u32, u32, u32, Vec<u8>)> {
+    let mut icon_info = ICONINFO::default();
+    GetIconInfo(hcursor, &mut icon_info).ok()?;
+
+    let screen_dc = GetDC(None);
+    let mem_dc = CreateCompatibleDC(screen_dc);
+
+    let pixels = if !icon_info.hbmColor.is_invalid() {
+        color_bitmap_to_rgba(mem_dc, icon_info.hbmColor, icon_info.hbmMask)
+    } else {
+        mask_bitmap_to_rgba(mem_dc, icon_info.hbmMask)
+    };
+
+    let _ = DeleteDC(mem_dc);
+    ReleaseDC(None, screen_dc);
+    if !icon_info.hbmColor.is_invalid() {
+        let _ = DeleteObject(icon_info.hbmColor);
+    }
+    let _ = DeleteObject(icon_info.hbmMask);
+
+    let (width, height, pixels) = pixels?;
+    Some((
+        width,
+        height,
+        icon_info.xHotspot,
+        icon_info.yHotspot,
+        pixels,
+    ))
+}
+
+/// Colored cursors carry a 32bpp `hbmColor` bitmap we can read directly with
+/// `GetDIBits`, swizzling BGRA to RGBA as we go. Legacy/app cursors often
+/// have no real alpha channel, so if every pixel comes back fully
+/// transparent we fall back to `hbmMask`'s AND plane for alpha instead.
+unsafe fn color_bitmap_to_rgba(
+    mem_dc: HDC,
+    hbm_color: HBITMAP,
+    hbm_mask: HBITMAP,
+) -> Option<(u32, u32, Vec<u8>)> {
+    let mut bitmap = BITMAP::default();
+    if GetObjectW(
+        hbm_color,
+        std::mem::size_of::<BITMAP>() as i32,
+        Some(&mut bitmap as *mut _ as *mut std::ffi::c_void),
+    ) == 0
+    {
+        return None;
+    }
+
+    let width = bitmap.bmWidth as u32;
+    let height = bitmap.bmHeight as u32;
+
+    // GetDIBits rejects a DC with the source bitmap currently selected into
+    // it (returns 0 / ERROR_INVALID_PARAMETER); it only needs a DC
+    // compatible with the bitmap's format, not the bitmap itself selected.
+    let mut bgra = vec![0u8; (width * height * 4) as usize];
+    let mut bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width as i32,
+            // Negative height requests a top-down DIB, matching the row order we want.
+            biHeight: -(height as i32),
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0 as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let copied = GetDIBits(
+        mem_dc,
+        hbm_color,
+        0,
+        height,
+        Some(bgra.as_mut_ptr() as *mut std::ffi::c_void),
+        &mut bmi,
+        DIB_RGB_COLORS,
+    );
+
+    if copied == 0 {
+        return None;
+    }
+
+    for pixel in bgra.chunks_exact_mut(4) {
+        pixel.swap(0, 2); // BGRA -> RGBA
+    }
+
+    if bgra.chunks_exact(4).all(|pixel| pixel[3] == 0) {
+        match mask_and_plane_alpha(mem_dc, hbm_mask, width, height) {
+            Some(alpha) => {
+                for (pixel, alpha) in bgra.chunks_exact_mut(4).zip(alpha) {
+                    pixel[3] = alpha;
+                }
+            }
+            None => {
+                for pixel in bgra.chunks_exact_mut(4) {
+                    pixel[3] = 255;
+                }
+            }
+        }
+    }
+
+    Some((width, height, bgra))
+}
+
+/// Reads a 1bpp mask bitmap's AND plane and converts it to a per-pixel alpha
+/// value (AND=1 means transparent). Used as the alpha fallback for color
+/// cursors with no real alpha channel; fully-monochrome cursors are decoded
+/// by `mask_bitmap_to_rgba` instead.
+unsafe fn mask_and_plane_alpha(
+    mem_dc: HDC,
+    hbm_mask: HBITMAP,
+    width: u32,
+    height: u32,
+) -> Option<Vec<u8>> {
+    let stride = ((width + 31) / 32 * 4) as usize;
+    let mut and_plane = vec![0u8; stride * height as usize];
+    let mut bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width as i32,
+            biHeight: -(height as i32),
+            biPlanes: 1,
+            biBitCount: 1,
+            biCompression: BI_RGB.0 as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let copied = GetDIBits(
+        mem_dc,
+        hbm_mask,
+        0,
+        height,
+        Some(and_plane.as_mut_ptr() as *mut std::ffi::c_void),
+        &mut bmi,
+        DIB_RGB_COLORS,
+    );
+    if copied == 0 {
+        return None;
+    }
+
+    Some(
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                if bit_at(&and_plane, stride, x, y) {
+                    0
+                } else {
+                    255
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Reads bit `(x, y)` out of a packed 1bpp DIB plane with the given row stride.
+fn bit_at(plane: &[u8], stride: usize, x: u32, y: u32) -> bool {
+    let byte = plane[y as usize * stride + (x / 8) as usize];
+    (byte >> (7 - (x % 8))) & 1 != 0
+}
+
+/// Monochrome cursors have no `hbmColor`; `hbmMask` is a single 1bpp bitmap
+/// double the cursor's height, with the AND mask stacked above the XOR mask.
+/// Per the MS docs: AND=0/XOR=0 -> opaque black, AND=0/XOR=1 -> opaque white,
+/// AND=1/XOR=0 -> transparent, AND=1/XOR=1 -> screen-inverted (approximated
+/// here as translucent gray, since true invert needs the framebuffer behind
+/// the cursor).
+unsafe fn mask_bitmap_to_rgba(mem_dc: HDC, hbm_mask: HBITMAP) -> Option<(u32, u32, Vec<u8>)> {
+    let mut bitmap = BITMAP::default();
+    if GetObjectW(
+        hbm_mask,
+        std::mem::size_of::<BITMAP>() as i32,
+        Some(&mut bitmap as *mut _ as *mut std::ffi::c_void),
+    ) == 0
+    {
+        return None;
+    }
+
+    let width = bitmap.bmWidth as u32;
+    let height = (bitmap.bmHeight / 2) as u32;
+    let stride = ((width + 31) / 32 * 4) as usize;
+
+    // See the comment in `color_bitmap_to_rgba`: GetDIBits needs the bitmap
+    // *not* selected into the DC it's given.
+    let mut mono = vec![0u8; stride * (height * 2) as usize];
+    let mut bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width as i32,
+            biHeight: -((height * 2) as i32),
+            biPlanes: 1,
+            biBitCount: 1,
+            biCompression: BI_RGB.0 as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let copied = GetDIBits(
+        mem_dc,
+        hbm_mask,
+        0,
+        height * 2,
+        Some(mono.as_mut_ptr() as *mut std::ffi::c_void),
+        &mut bmi,
+        DIB_RGB_COLORS,
+    );
+
+    if copied == 0 {
+        return None;
+    }
+
+    let and_plane = &mono[..stride * height as usize];
+    let xor_plane = &mono[stride * height as usize..];
+
+    let mut rgba = vec![0u8; (width * height * 4) as usize];
+    for y in 0..height {
+        for x in 0..width {
+            let and_bit = bit_at(and_plane, stride, x, y);
+            let xor_bit = bit_at(xor_plane, stride, x, y);
+            let idx = ((y * width + x) * 4) as usize;
+            let (rgb, alpha) = match (and_bit, xor_bit) {
+                (false, false) => (0u8, 255u8),
+                (false, true) => (255u8, 255u8),
+                (true, false) => (0u8, 0u8),
+                (true, true) => (128u8, 128u8),
+            };
+            rgba[idx] = rgb;
+            rgba[idx + 1] = rgb;
+            rgba[idx + 2] = rgb;
+            rgba[idx + 3] = alpha;
+        }
+    }
+
+    Some((width, height, rgba))
+}
+
 /// Keeps handles to default cursor.
 /// Read more: [MS Doc - About Cursors](https://learn.microsoft.com/en-us/windows/win32/menurc/about-cursors)
 pub struct DefaultCursors {
@@ -131,91 +428,98 @@ unsafe fn pid_to_exe_path(pid: u32) -> Result<PathBuf, windows::core::Error> {
     Ok(PathBuf::from(os_str))
 }
 
-pub fn get_on_screen_windows() -> Vec<Window> {
-    let mut windows = Vec::<Window>::new();
-
-    unsafe extern "system" fn enum_window_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
-        if hwnd.0 == 0 {
-            return TRUE;
-        }
-        let windows = &mut *(lparam.0 as *mut Vec<Window>);
-
-        if !IsWindowVisible(hwnd).as_bool() {
-            return TRUE;
-        }
+/// Applies the same cloaking/visibility/exe-path filtering `get_on_screen_windows`
+/// has always used, and builds a `Window` from whatever passes. Shared with
+/// `WindowEventWatcher` so a pushed event and a fresh enumeration agree on
+/// what counts as a capturable window.
+unsafe fn build_window(hwnd: HWND) -> Option<Window> {
+    if hwnd.0 == 0 || !IsWindowVisible(hwnd).as_bool() {
+        return None;
+    }
 
-        let mut pvattribute_cloaked = 0u32;
-        DwmGetWindowAttribute(
-            hwnd,
-            DWMWA_CLOAKED,
-            &mut pvattribute_cloaked as *mut _ as *mut std::ffi::c_void,
-            std::mem::size_of::<u32>() as u32,
-        )
-        .ok();
+    let mut pvattribute_cloaked = 0u32;
+    DwmGetWindowAttribute(
+        hwnd,
+        DWMWA_CLOAKED,
+        &mut pvattribute_cloaked as *mut _ as *mut std::ffi::c_void,
+        std::mem::size_of::<u32>() as u32,
+    )
+    .ok();
 
-        if pvattribute_cloaked != 0 {
-            return TRUE;
-        }
+    if pvattribute_cloaked != 0 {
+        return None;
+    }
 
-        let mut process_id = 0;
-        let _thrad_id = GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+    let mut process_id = 0;
+    let _thrad_id = GetWindowThreadProcessId(hwnd, Some(&mut process_id));
 
-        let wnamelen = GetWindowTextLengthW(hwnd);
-        if wnamelen == 0 {
-            return TRUE;
-        }
-        let mut wname = [0u16; 512];
-        let len = GetWindowTextW(hwnd, &mut wname);
+    let wnamelen = GetWindowTextLengthW(hwnd);
+    if wnamelen == 0 {
+        return None;
+    }
+    let mut wname = [0u16; 512];
+    let len = GetWindowTextW(hwnd, &mut wname);
 
-        let owner_process_path = match pid_to_exe_path(process_id) {
-            Ok(path) => path,
-            Err(_) => return TRUE,
-        };
+    let owner_process_path = pid_to_exe_path(process_id).ok()?;
 
-        if owner_process_path.starts_with("C:\\Windows\\SystemApps") {
-            return TRUE;
-        }
+    if owner_process_path.starts_with("C:\\Windows\\SystemApps") {
+        return None;
+    }
 
-        let owner_name = match owner_process_path.file_stem() {
-            Some(exe_name) => exe_name.to_string_lossy().into_owned(),
-            None => owner_process_path.to_string_lossy().into_owned(),
-        };
+    let owner_name = match owner_process_path.file_stem() {
+        Some(exe_name) => exe_name.to_string_lossy().into_owned(),
+        None => owner_process_path.to_string_lossy().into_owned(),
+    };
 
-        // Windows 10 build 1607 or later
-        // Credits: TAO src/platform_impl/windows/dpi.rs
-        const BASE_DPI: u32 = 96;
-        let dpi = match GetDpiForWindow(hwnd) {
-            0 => BASE_DPI,
-            dpi => dpi,
-        } as i32;
+    // Windows 10 build 1607 or later
+    // Credits: TAO src/platform_impl/windows/dpi.rs
+    const BASE_DPI: u32 = 96;
+    let dpi = match GetDpiForWindow(hwnd) {
+        0 => BASE_DPI,
+        dpi => dpi,
+    } as i32;
 
-        let scale_factor = dpi as f64 / BASE_DPI as f64;
-        let mut rect = RECT::default();
-        GetWindowRect(hwnd, &mut rect).ok();
+    let scale_factor = dpi as f64 / BASE_DPI as f64;
+    let mut rect = RECT::default();
+    GetWindowRect(hwnd, &mut rect).ok();
 
-        let lpos_x = rect.left as f64 / scale_factor;
-        let lpos_y = rect.top as f64 / scale_factor;
+    let lpos_x = rect.left as f64 / scale_factor;
+    let lpos_y = rect.top as f64 / scale_factor;
 
-        let window = Window {
-            window_id: hwnd.0 as u32,
-            name: String::from_utf16_lossy(&wname[..len as usize]),
-            owner_name,
-            process_id,
-            bounds: Bounds {
-                x: match lpos_x {
-                    x if x.is_sign_negative() => 0.0,
-                    _ => lpos_x,
-                },
-                y: match lpos_y {
-                    y if y.is_sign_negative() => 0.0,
-                    _ => lpos_y,
-                },
-                width: (rect.right - rect.left) as f64 / scale_factor,
-                height: (rect.bottom - rect.top) as f64 / scale_factor,
+    Some(Window {
+        window_id: hwnd.0 as u32,
+        name: String::from_utf16_lossy(&wname[..len as usize]),
+        owner_name,
+        process_id,
+        // Filled in by the caller, which knows this window's position in
+        // EnumWindows' (already top-to-bottom) ordering.
+        z_order: 0,
+        bounds: Bounds {
+            x: match lpos_x {
+                x if x.is_sign_negative() => 0.0,
+                _ => lpos_x,
             },
-        };
+            y: match lpos_y {
+                y if y.is_sign_negative() => 0.0,
+                _ => lpos_y,
+            },
+            width: (rect.right - rect.left) as f64 / scale_factor,
+            height: (rect.bottom - rect.top) as f64 / scale_factor,
+        },
+    })
+}
+
+pub fn get_on_screen_windows() -> Vec<Window> {
+    let mut windows = Vec::<Window>::new();
 
-        windows.push(window);
+    unsafe extern "system" fn enum_window_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let windows = &mut *(lparam.0 as *mut Vec<Window>);
+        if let Some(mut window) = build_window(hwnd) {
+            // EnumWindows visits windows top-to-bottom in z-order, so the
+            // running count we've pushed so far is this window's z-order.
+            window.z_order = windows.len() as u32;
+            windows.push(window);
+        }
         TRUE
     }
 
@@ -228,17 +532,66 @@ pub fn get_on_screen_windows() -> Vec<Window> {
     windows
 }
 
-pub fn monitor_bounds(id: u32) -> Bounds {
-    let bounds = Bounds::default();
-    let idx = 0u32;
-    let lparams = (id, idx, bounds);
+/// A single display, keyed by its stable GDI device name (`szDevice`, e.g.
+/// `\\.\DISPLAY1`) so callers can correlate a window's monitor with its
+/// bounds and scale factor across mixed-DPI multi-monitor setups.
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    pub device_name: String,
+    pub display_name: String,
+    pub bounds: Bounds,
+    pub work_area: Bounds,
+    pub is_primary: bool,
+    pub scale_factor: f64,
+    pub refresh_rate: u32,
+}
+
+/// `szDevice` names the adapter output (e.g. `\\.\DISPLAY1`), not the monitor
+/// plugged into it. Passing it as the device to `EnumDisplayDevicesW` lists
+/// the monitors attached to that output; this walks `iDevNum` from 0 and
+/// returns the `DeviceString` of the first active one, or `None` if the
+/// enumeration ends without one.
+unsafe fn monitor_friendly_name(adapter_device_name: &[u16; 32]) -> Option<String> {
+    let mut i = 0u32;
+    loop {
+        let mut child = DISPLAY_DEVICEW {
+            cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+            ..Default::default()
+        };
+        if !EnumDisplayDevicesW(
+            PCWSTR(adapter_device_name.as_ptr()),
+            i,
+            &mut child,
+            EDD_GET_DEVICE_INTERFACE_NAME,
+        )
+        .as_bool()
+        {
+            return None;
+        }
+
+        if child.StateFlags & DISPLAY_DEVICE_ACTIVE != 0 {
+            return Some(
+                OsString::from_wide(&child.DeviceString)
+                    .to_string_lossy()
+                    .trim_end_matches('\0')
+                    .to_owned(),
+            );
+        }
+
+        i += 1;
+    }
+}
+
+pub fn get_monitors() -> Vec<Monitor> {
+    let mut monitors = Vec::<Monitor>::new();
+
     unsafe extern "system" fn enum_monitor_proc(
         hmonitor: HMONITOR,
         _hdc: HDC,
         _lprc_clip: *mut RECT,
         lparam: LPARAM,
     ) -> BOOL {
-        let (target_id, idx, bounds) = &mut *(lparam.0 as *mut (u32, u32, Bounds));
+        let monitors = &mut *(lparam.0 as *mut Vec<Monitor>);
 
         let mut minfo = MONITORINFOEXW::default();
         minfo.monitorInfo.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
@@ -251,20 +604,60 @@ pub fn monitor_bounds(id: u32) -> Bounds {
             return TRUE;
         };
 
-        *idx += 1;
-        if idx != target_id {
-            return TRUE;
-        }
-
         let mi = minfo.monitorInfo;
-        *bounds = Bounds {
-            x: mi.rcMonitor.left as f64,
-            y: mi.rcMonitor.top as f64,
-            width: (mi.rcMonitor.right - mi.rcMonitor.left) as f64,
-            height: (mi.rcMonitor.bottom - mi.rcMonitor.top) as f64,
+        let device_name = OsString::from_wide(&minfo.szDevice)
+            .to_string_lossy()
+            .trim_end_matches('\0')
+            .to_owned();
+
+        let display_name =
+            monitor_friendly_name(&minfo.szDevice).unwrap_or_else(|| device_name.clone());
+
+        let mut dpi_x = 0u32;
+        let mut dpi_y = 0u32;
+        let scale_factor =
+            match GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) {
+                Ok(_) => dpi_x as f64 / 96.0,
+                Err(_) => 1.0,
+            };
+
+        let mut devmode = DEVMODEW {
+            dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+            ..Default::default()
+        };
+        let refresh_rate = if EnumDisplaySettingsW(
+            PCWSTR(minfo.szDevice.as_ptr()),
+            ENUM_CURRENT_SETTINGS,
+            &mut devmode,
+        )
+        .as_bool()
+        {
+            devmode.dmDisplayFrequency
+        } else {
+            0
         };
 
-        FALSE
+        monitors.push(Monitor {
+            device_name,
+            display_name,
+            bounds: Bounds {
+                x: mi.rcMonitor.left as f64,
+                y: mi.rcMonitor.top as f64,
+                width: (mi.rcMonitor.right - mi.rcMonitor.left) as f64,
+                height: (mi.rcMonitor.bottom - mi.rcMonitor.top) as f64,
+            },
+            work_area: Bounds {
+                x: mi.rcWork.left as f64,
+                y: mi.rcWork.top as f64,
+                width: (mi.rcWork.right - mi.rcWork.left) as f64,
+                height: (mi.rcWork.bottom - mi.rcWork.top) as f64,
+            },
+            is_primary: mi.dwFlags & MONITORINFOF_PRIMARY != 0,
+            scale_factor,
+            refresh_rate,
+        });
+
+        TRUE
     }
 
     let _ = unsafe {
@@ -272,57 +665,592 @@ pub fn monitor_bounds(id: u32) -> Bounds {
             None,
             None,
             Some(enum_monitor_proc),
-            LPARAM(std::ptr::addr_of!(lparams) as isize),
-        );
+            LPARAM(core::ptr::addr_of_mut!(monitors) as isize),
+        )
     };
-    bounds
+    monitors
+}
+
+/// Kept for existing callers of the old monitor API. `id` is a 1-based index
+/// into `get_monitors()`'s enumeration order, which `EnumDisplayMonitors`
+/// doesn't guarantee is stable across calls — prefer `get_monitors` directly.
+#[deprecated(note = "use get_monitors instead; id is a fragile enumeration index")]
+pub fn monitor_bounds(id: u32) -> Bounds {
+    get_monitors()
+        .get(id.saturating_sub(1) as usize)
+        .map(|monitor| monitor.bounds)
+        .unwrap_or_default()
 }
 
+/// Kept for existing callers of the old monitor API. See [`monitor_bounds`]
+/// for why the keys here are a fragile enumeration index rather than a
+/// stable identifier.
+#[deprecated(note = "use get_monitors instead; keys are a fragile enumeration index")]
 pub fn window_names() -> HashMap<u32, String> {
-    let mut names = HashMap::new();
-    unsafe extern "system" fn monitor_enum_proc(
-        hmonitor: HMONITOR,
-        _hdc: HDC,
-        _lprc_clip: *mut RECT,
-        lparam: LPARAM,
-    ) -> BOOL {
-        let monitors = &mut *(lparam.0 as *mut HashMap<u32, String>);
+    get_monitors()
+        .into_iter()
+        .enumerate()
+        .map(|(index, monitor)| (index as u32 + 1, monitor.display_name))
+        .collect()
+}
 
-        let mut minfo = MONITORINFOEXW::default();
-        minfo.monitorInfo.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
-        if !GetMonitorInfoW(
-            hmonitor,
-            &mut minfo as *mut MONITORINFOEXW as *mut MONITORINFO,
+/// A window lifecycle/visibility/position change pushed by [`WindowEventWatcher`].
+#[derive(Debug, Clone)]
+pub enum WindowEvent {
+    Focused(u32),
+    Moved { window_id: u32, bounds: Bounds },
+    Created(u32),
+    Destroyed(u32),
+    Shown(u32),
+    Hidden(u32),
+}
+
+thread_local! {
+    static EVENT_SENDER: std::cell::RefCell<Option<std::sync::mpsc::Sender<WindowEvent>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+unsafe extern "system" fn win_event_proc(
+    _hook: HWINEVENTHOOK,
+    event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    id_child: i32,
+    _id_event_thread: u32,
+    _event_time: u32,
+) {
+    // Only events about a window object itself (OBJID_WINDOW, CHILDID_SELF),
+    // not about an element inside it. Child windows are not filtered out here.
+    if id_object != OBJID_WINDOW.0 || id_child != 0 {
+        return;
+    }
+
+    let window_event = match event {
+        EVENT_SYSTEM_FOREGROUND => build_window(hwnd).map(|w| WindowEvent::Focused(w.window_id)),
+        EVENT_OBJECT_LOCATIONCHANGE => build_window(hwnd).map(|w| WindowEvent::Moved {
+            window_id: w.window_id,
+            bounds: w.bounds,
+        }),
+        EVENT_OBJECT_SHOW => build_window(hwnd).map(|w| WindowEvent::Shown(w.window_id)),
+        EVENT_OBJECT_CREATE => build_window(hwnd).map(|w| WindowEvent::Created(w.window_id)),
+        // The window is already being torn down by this point, so its title
+        // and exe path may no longer be queryable; `build_window` would just
+        // filter it out. Report the raw id instead of silently dropping it.
+        EVENT_OBJECT_DESTROY => Some(WindowEvent::Destroyed(hwnd.0 as u32)),
+        EVENT_OBJECT_HIDE => Some(WindowEvent::Hidden(hwnd.0 as u32)),
+        _ => None,
+    };
+
+    let Some(window_event) = window_event else {
+        return;
+    };
+
+    EVENT_SENDER.with(|sender| {
+        if let Some(tx) = sender.borrow().as_ref() {
+            let _ = tx.send(window_event.clone());
+        }
+    });
+}
+
+/// Pushes window focus/move/create/destroy/show/hide events as they happen,
+/// via out-of-context `SetWinEventHook`s pumped on a dedicated thread, so
+/// callers following a window don't have to re-poll `get_on_screen_windows`.
+pub struct WindowEventWatcher {
+    thread_id: u32,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl WindowEventWatcher {
+    pub fn spawn() -> (Self, std::sync::mpsc::Receiver<WindowEvent>) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let (thread_id_tx, thread_id_rx) = std::sync::mpsc::channel();
+
+        let join_handle = std::thread::spawn(move || unsafe {
+            EVENT_SENDER.with(|sender| *sender.borrow_mut() = Some(tx));
+            thread_id_tx.send(GetCurrentThreadId()).ok();
+
+            let hooks = [
+                SetWinEventHook(
+                    EVENT_SYSTEM_FOREGROUND,
+                    EVENT_SYSTEM_FOREGROUND,
+                    None,
+                    Some(win_event_proc),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                ),
+                SetWinEventHook(
+                    EVENT_OBJECT_CREATE,
+                    EVENT_OBJECT_HIDE,
+                    None,
+                    Some(win_event_proc),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                ),
+                SetWinEventHook(
+                    EVENT_OBJECT_LOCATIONCHANGE,
+                    EVENT_OBJECT_LOCATIONCHANGE,
+                    None,
+                    Some(win_event_proc),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                ),
+            ];
+
+            // Out-of-context hooks are only delivered to a thread pumping
+            // messages, so this thread exists solely to run that loop.
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            for hook in hooks {
+                let _ = UnhookWinEvent(hook);
+            }
+        });
+
+        let thread_id = thread_id_rx
+            .recv()
+            .expect("window event thread died before reporting its id");
+
+        (
+            Self {
+                thread_id,
+                join_handle: Some(join_handle),
+            },
+            rx,
         )
-        .as_bool()
-        {
+    }
+}
+
+impl Drop for WindowEventWatcher {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PostThreadMessageW(self.thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+        }
+        if let Some(join_handle) = self.join_handle.take() {
+            let _ = join_handle.join();
+        }
+    }
+}
+
+/// Returns the raw-screen-pixel rectangles of `window_id` that aren't covered
+/// by any window above it in z-order, so callers can warn when a recorded
+/// window is partially hidden or draw an accurate "this area won't be
+/// captured" overlay.
+///
+/// Deliberately re-enumerates in raw pixels rather than reusing
+/// `get_on_screen_windows()`'s `Window.bounds`: those are divided by each
+/// window's *own* DPI scale factor (and negative x/y clamped to 0), so two
+/// windows on monitors with different scale factors would otherwise end up
+/// in different coordinate spaces and the overlap math would be wrong.
+pub fn visible_region(window_id: u32) -> Vec<Bounds> {
+    let windows = unsafe { enum_raw_windows() };
+    let Some(target) = windows.iter().find(|w| w.window_id == window_id) else {
+        return Vec::new();
+    };
+    let target_z_order = target.z_order;
+
+    let mut remaining = vec![target.bounds];
+    for window in &windows {
+        if remaining.is_empty() {
+            break;
+        }
+        if window.z_order >= target_z_order {
+            continue;
+        }
+        remaining = remaining
+            .into_iter()
+            .flat_map(|bounds| subtract_bounds(bounds, window.bounds))
+            .collect();
+    }
+    remaining
+}
+
+/// A window's raw, unscaled screen-pixel bounds and z-order, used only for
+/// the occlusion math in `visible_region` (see its doc comment for why this
+/// can't just reuse the public, per-window-DPI-scaled `Window.bounds`).
+struct RawWindow {
+    window_id: u32,
+    z_order: u32,
+    bounds: Bounds,
+}
+
+unsafe fn enum_raw_windows() -> Vec<RawWindow> {
+    let mut windows = Vec::<RawWindow>::new();
+
+    unsafe extern "system" fn enum_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let windows = &mut *(lparam.0 as *mut Vec<RawWindow>);
+
+        // Reuse the same cloaking/visibility/exe-path filtering as
+        // `get_on_screen_windows`, just discarding its DPI-scaled bounds.
+        if build_window(hwnd).is_none() {
             return TRUE;
+        }
+
+        let mut rect = RECT::default();
+        if GetWindowRect(hwnd, &mut rect).is_err() {
+            return TRUE;
+        }
+
+        windows.push(RawWindow {
+            window_id: hwnd.0 as u32,
+            z_order: windows.len() as u32,
+            bounds: Bounds {
+                x: rect.left as f64,
+                y: rect.top as f64,
+                width: (rect.right - rect.left) as f64,
+                height: (rect.bottom - rect.top) as f64,
+            },
+        });
+        TRUE
+    }
+
+    let _ = EnumWindows(
+        Some(enum_proc),
+        LPARAM(core::ptr::addr_of_mut!(windows) as isize),
+    );
+    windows
+}
+
+/// Rectangle subtraction: returns the pieces of `a` left over after removing
+/// whatever part of it `b` overlaps (up to four axis-aligned rectangles).
+fn subtract_bounds(a: Bounds, b: Bounds) -> Vec<Bounds> {
+    let (ax0, ay0, ax1, ay1) = (a.x, a.y, a.x + a.width, a.y + a.height);
+    let (bx0, by0, bx1, by1) = (b.x, b.y, b.x + b.width, b.y + b.height);
+
+    if bx1 <= ax0 || bx0 >= ax1 || by1 <= ay0 || by0 >= ay1 {
+        return vec![a];
+    }
+
+    let mut pieces = Vec::with_capacity(4);
+
+    if by0 > ay0 {
+        pieces.push(Bounds {
+            x: ax0,
+            y: ay0,
+            width: a.width,
+            height: by0 - ay0,
+        });
+    }
+    if by1 < ay1 {
+        pieces.push(Bounds {
+            x: ax0,
+            y: by1,
+            width: a.width,
+            height: ay1 - by1,
+        });
+    }
+
+    let mid_y0 = ay0.max(by0);
+    let mid_y1 = ay1.min(by1);
+    if bx0 > ax0 {
+        pieces.push(Bounds {
+            x: ax0,
+            y: mid_y0,
+            width: bx0 - ax0,
+            height: mid_y1 - mid_y0,
+        });
+    }
+    if bx1 < ax1 {
+        pieces.push(Bounds {
+            x: bx1,
+            y: mid_y0,
+            width: ax1 - bx1,
+            height: mid_y1 - mid_y0,
+        });
+    }
+
+    pieces
+}
+
+#[cfg(test)]
+mod subtract_bounds_tests {
+    use super::*;
+
+    fn rect(bounds: &Bounds) -> (f64, f64, f64, f64) {
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    #[test]
+    fn disjoint_rects_are_untouched() {
+        let a = Bounds {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+        let b = Bounds {
+            x: 200.0,
+            y: 200.0,
+            width: 50.0,
+            height: 50.0,
         };
 
-        let mut display_device = DISPLAY_DEVICEW::default();
-        display_device.cb = std::mem::size_of::<DISPLAY_DEVICEW>() as u32;
+        let result = subtract_bounds(a, b);
 
-        if !EnumDisplayDevicesW(PCWSTR(minfo.szDevice.as_ptr()), 0, &mut display_device, 0)
-            .as_bool()
-        {
-            return TRUE;
+        assert_eq!(result.len(), 1);
+        assert_eq!(rect(&result[0]), rect(&a));
+    }
+
+    #[test]
+    fn fully_covered_rect_leaves_nothing() {
+        let a = Bounds {
+            x: 10.0,
+            y: 10.0,
+            width: 50.0,
+            height: 50.0,
+        };
+        let b = Bounds {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
         };
 
-        let device_name = OsString::from_wide(&display_device.DeviceName)
-            .to_string_lossy()
-            .into_owned();
-        let num = monitors.len() as u32;
-        monitors.insert(num, device_name);
-        TRUE
+        assert!(subtract_bounds(a, b).is_empty());
     }
 
-    let _ = unsafe {
-        EnumDisplayMonitors(
-            None,
-            None,
-            Some(monitor_enum_proc),
-            LPARAM(core::ptr::addr_of_mut!(names) as isize),
+    #[test]
+    fn partial_overlap_in_the_middle_yields_four_pieces() {
+        // `b` punches a hole in the middle of `a`, leaving a picture-frame
+        // of four rectangles: above, below, left, and right of the overlap.
+        let a = Bounds {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+        let b = Bounds {
+            x: 25.0,
+            y: 25.0,
+            width: 50.0,
+            height: 50.0,
+        };
+
+        let result = subtract_bounds(a, b);
+
+        assert_eq!(result.len(), 4);
+
+        let total_area: f64 = result.iter().map(|r| r.width * r.height).sum();
+        assert_eq!(total_area, a.width * a.height - b.width * b.height);
+
+        // None of the remaining pieces should overlap the hole `b` punched.
+        for piece in &result {
+            let overlaps_b = piece.x < b.x + b.width
+                && piece.x + piece.width > b.x
+                && piece.y < b.y + b.height
+                && piece.y + piece.height > b.y;
+            assert!(!overlaps_b);
+        }
+    }
+}
+
+fn wide_null(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// A single high-frequency cursor position sample, in screen coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct CursorSample {
+    pub x: i32,
+    pub y: i32,
+    pub timestamp: std::time::Instant,
+}
+
+struct RawCursorState {
+    sender: std::sync::mpsc::Sender<CursorSample>,
+    position: (i32, i32),
+}
+
+thread_local! {
+    static RAW_CURSOR_STATE: std::cell::RefCell<Option<RawCursorState>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+unsafe extern "system" fn raw_cursor_wndproc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if msg == WM_INPUT {
+        handle_raw_input(lparam);
+        return LRESULT(0);
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+unsafe fn handle_raw_input(lparam: LPARAM) {
+    let mut size = 0u32;
+    let header_size = std::mem::size_of::<RAWINPUTHEADER>() as u32;
+    GetRawInputData(HRAWINPUT(lparam.0), RID_INPUT, None, &mut size, header_size);
+    if size == 0 {
+        return;
+    }
+
+    let mut buf = vec![0u8; size as usize];
+    let copied = GetRawInputData(
+        HRAWINPUT(lparam.0),
+        RID_INPUT,
+        Some(buf.as_mut_ptr() as *mut std::ffi::c_void),
+        &mut size,
+        header_size,
+    );
+    if copied == u32::MAX || copied != size {
+        return;
+    }
+
+    let raw = &*(buf.as_ptr() as *const RAWINPUT);
+    if raw.header.dwType != RIM_TYPEMOUSE.0 {
+        return;
+    }
+
+    RAW_CURSOR_STATE.with(|cell| {
+        let mut state = cell.borrow_mut();
+        let Some(state) = state.as_mut() else {
+            return;
+        };
+
+        let mouse = raw.data.mouse;
+        if mouse.usFlags & MOUSE_MOVE_ABSOLUTE == MOUSE_MOVE_ABSOLUTE {
+            // In absolute mode lLastX/lLastY aren't screen pixels: they're
+            // normalized to the 0..=65535 range across the virtual desktop
+            // (the common case for RDP sessions, tablets, and VMs), so they
+            // need rescaling against the virtual-screen metrics first.
+            let origin_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
+            let origin_y = GetSystemMetrics(SM_YVIRTUALSCREEN);
+            let width = GetSystemMetrics(SM_CXVIRTUALSCREEN);
+            let height = GetSystemMetrics(SM_CYVIRTUALSCREEN);
+
+            let normalized_x = mouse.lLastX as f64 / 65535.0;
+            let normalized_y = mouse.lLastY as f64 / 65535.0;
+
+            state.position = (
+                origin_x + (normalized_x * width as f64).round() as i32,
+                origin_y + (normalized_y * height as f64).round() as i32,
+            );
+        } else {
+            state.position.0 += mouse.lLastX;
+            state.position.1 += mouse.lLastY;
+        }
+
+        let _ = state.sender.send(CursorSample {
+            x: state.position.0,
+            y: state.position.1,
+            timestamp: std::time::Instant::now(),
+        });
+    });
+}
+
+/// Samples the cursor position every time the OS delivers a `WM_INPUT`
+/// mouse event, rather than polling `GetCursorInfo` once per captured frame,
+/// so fast pointer movement doesn't get under-sampled in the recording.
+///
+/// Creates a message-only window (parented to `HWND_MESSAGE`) on a dedicated
+/// thread so it can register for raw input with `RIDEV_INPUTSINK` and keep
+/// receiving samples even while Cap's windows don't have focus.
+pub struct RawCursorTracker {
+    thread_id: u32,
+    join_handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl RawCursorTracker {
+    pub fn spawn() -> (Self, std::sync::mpsc::Receiver<CursorSample>) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let (thread_id_tx, thread_id_rx) = std::sync::mpsc::channel();
+
+        let join_handle = std::thread::spawn(move || unsafe {
+            let mut baseline = POINT::default();
+            let _ = GetCursorPos(&mut baseline);
+            RAW_CURSOR_STATE.with(|cell| {
+                *cell.borrow_mut() = Some(RawCursorState {
+                    sender: tx,
+                    position: (baseline.x, baseline.y),
+                })
+            });
+
+            thread_id_tx.send(GetCurrentThreadId()).ok();
+
+            let class_name = wide_null("CapRawCursorTrackerClass");
+            let hinstance = GetModuleHandleW(None).unwrap_or_default();
+
+            let wnd_class = WNDCLASSW {
+                lpfnWndProc: Some(raw_cursor_wndproc),
+                hInstance: hinstance.into(),
+                lpszClassName: PCWSTR(class_name.as_ptr()),
+                ..Default::default()
+            };
+            RegisterClassW(&wnd_class);
+
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                PCWSTR(class_name.as_ptr()),
+                PCWSTR::null(),
+                WS_DISABLED,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                CW_USEDEFAULT,
+                HWND_MESSAGE,
+                None,
+                hinstance,
+                None,
+            );
+
+            let device = RAWINPUTDEVICE {
+                usUsagePage: 0x01,
+                usUsage: 0x02,
+                dwFlags: RIDEV_INPUTSINK,
+                hwndTarget: hwnd,
+            };
+            let _ =
+                RegisterRawInputDevices(&[device], std::mem::size_of::<RAWINPUTDEVICE>() as u32);
+
+            // Out-of-focus raw input delivery still requires a message pump.
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            let unregister = RAWINPUTDEVICE {
+                usUsagePage: 0x01,
+                usUsage: 0x02,
+                dwFlags: RIDEV_REMOVE,
+                hwndTarget: HWND::default(),
+            };
+            let _ = RegisterRawInputDevices(
+                &[unregister],
+                std::mem::size_of::<RAWINPUTDEVICE>() as u32,
+            );
+            let _ = DestroyWindow(hwnd);
+        });
+
+        let thread_id = thread_id_rx
+            .recv()
+            .expect("raw cursor tracker thread died before reporting its id");
+
+        (
+            Self {
+                thread_id,
+                join_handle: Some(join_handle),
+            },
+            rx,
         )
-    };
-    names
-}
\ No newline at end of file
+    }
+}
+
+impl Drop for RawCursorTracker {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PostThreadMessageW(self.thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+        }
+        if let Some(join_handle) = self.join_handle.take() {
+            let _ = join_handle.join();
+        }
+    }
+}